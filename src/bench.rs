@@ -0,0 +1,144 @@
+//! A reusable harness for benchmarking [`Model`] implementations, so example models such as
+//! [`crate::test_util::binary_clock::BinaryClock`] and
+//! [`crate::test_util::linear_equation_solver::LinearEquation`] can double as standard workloads
+//! for tracking checker performance regressions across commits.
+
+use crate::Model;
+use std::collections::{HashSet, VecDeque};
+use std::hash::Hash;
+use std::mem::size_of;
+use std::time::Instant;
+
+/// A performance report produced by running a [`Benchmark`], comparable across commits.
+#[derive(Clone, Debug)]
+pub struct BenchReport {
+    pub name: &'static str,
+    /// Transitions generated via `Model::next_state`, including ones that landed on an
+    /// already-visited state and were discarded. Initial states are not counted here.
+    pub states_generated: u64,
+    /// Distinct states actually visited: the distinct initial states plus every generated state
+    /// that had not been visited before.
+    pub distinct_states: u64,
+    pub elapsed_secs: f64,
+    pub states_per_sec: f64,
+    pub peak_memory_bytes: u64,
+}
+
+impl BenchReport {
+    /// Renders this report as JSON, so CI can diff it against a prior commit's report.
+    pub fn to_json(&self) -> String {
+        format!(
+            "{{\"name\":{:?},\"states_generated\":{},\"distinct_states\":{},\"elapsed_secs\":{},\"states_per_sec\":{},\"peak_memory_bytes\":{}}}",
+            self.name,
+            self.states_generated,
+            self.distinct_states,
+            self.elapsed_secs,
+            self.states_per_sec,
+            self.peak_memory_bytes,
+        )
+    }
+}
+
+/// A standard, repeatable workload for tracking checker performance. `build` wraps a [`Model`]
+/// as a benchmark fixture; `run` exercises the checker against it and reports on the result.
+pub trait Benchmark: Sized {
+    /// The model this benchmark exercises.
+    type Model: Model;
+
+    /// Wraps `model` as a benchmark.
+    fn build(model: Self::Model) -> Self;
+
+    /// Runs the checker against the wrapped model and reports on its performance.
+    fn run(&self) -> BenchReport;
+}
+
+/// The standard [`Benchmark`]: exhaustively explores a model's reachable states breadth-first,
+/// ignoring its properties, so every reachable state is counted regardless of how quickly a
+/// `sometimes` property would otherwise have ended a [`crate::Checker::check`] run.
+pub struct StateSpaceBenchmark<M: Model> {
+    model: M,
+}
+
+impl<M: Model> Benchmark for StateSpaceBenchmark<M>
+where
+    M::State: Clone + Eq + Hash,
+{
+    type Model = M;
+
+    fn build(model: M) -> Self {
+        StateSpaceBenchmark { model }
+    }
+
+    fn run(&self) -> BenchReport {
+        let start = Instant::now();
+        let mut visited: HashSet<M::State> = HashSet::new();
+        let mut frontier: VecDeque<M::State> = VecDeque::new();
+        let mut states_generated: u64 = 0;
+        let mut peak_memory_bytes: u64 = 0;
+
+        for state in self.model.init_states() {
+            if visited.insert(state.clone()) {
+                frontier.push_back(state);
+            }
+        }
+
+        let mut actions = Vec::new();
+        while let Some(state) = frontier.pop_front() {
+            let live_states = (visited.len() + frontier.len()) as u64;
+            peak_memory_bytes = peak_memory_bytes.max(live_states * size_of::<M::State>() as u64);
+
+            actions.clear();
+            self.model.actions(&state, &mut actions);
+            for action in actions.drain(..) {
+                if let Some(next) = self.model.next_state(&state, action) {
+                    states_generated += 1;
+                    if visited.insert(next.clone()) {
+                        frontier.push_back(next);
+                    }
+                }
+            }
+        }
+
+        let elapsed_secs = start.elapsed().as_secs_f64();
+        let distinct_states = visited.len() as u64;
+        BenchReport {
+            name: std::any::type_name::<M>(),
+            states_generated,
+            distinct_states,
+            elapsed_secs,
+            states_per_sec: if elapsed_secs == 0.0 {
+                0.0
+            } else {
+                distinct_states as f64 / elapsed_secs
+            },
+            peak_memory_bytes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::binary_clock::BinaryClock;
+    use crate::test_util::linear_equation_solver::LinearEquation;
+
+    #[test]
+    fn state_space_benchmark_counts_binary_clock_exactly() {
+        let report = StateSpaceBenchmark::build(BinaryClock).run();
+        assert_eq!(report.distinct_states, 2);
+        assert_eq!(report.states_generated, 2);
+    }
+
+    #[test]
+    fn states_generated_and_distinct_states_diverge_once_duplicates_are_counted() {
+        let report = StateSpaceBenchmark::build(LinearEquation { a: 2, b: 3, c: 7 }).run();
+        assert_eq!(report.distinct_states, 256 * 256);
+        assert!(
+            report.states_generated > report.distinct_states,
+            "states_generated ({}) should exceed distinct_states ({}) once duplicate \
+             transitions are counted instead of only distinct pops",
+            report.states_generated,
+            report.distinct_states,
+        );
+    }
+}
@@ -0,0 +1,495 @@
+//! Explores the state graph of a [`Model`] and evaluates its [`Property`] list against every
+//! state that is discovered.
+
+use crate::{Expectation, Model};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+
+/// The progress interval, in states visited, that a new [`Checker`] starts with.
+/// [`Checker::on_progress`] replaces it with its own `interval` argument.
+const DEFAULT_PROGRESS_INTERVAL: u64 = 10_000;
+
+/// Determines the order in which a [`Checker`] pulls unexplored states off its frontier.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SearchMode {
+    /// Explore states in the order they were discovered (FIFO).
+    BreadthFirst,
+    /// Explore each state's descendants before its siblings (LIFO).
+    DepthFirst,
+    /// Explore the state with the lowest [`Model::heuristic`] value first.
+    BestFirst,
+}
+
+/// A sequence of states, paired with the action that produced each one, from an initial state to
+/// a discovered state. The initial state has no associated action.
+#[derive(Clone, Debug)]
+pub struct Path<State, Action>(Vec<(State, Option<Action>)>);
+
+impl<State, Action> Path<State, Action> {
+    /// The discovered state at the end of this path.
+    pub fn last_state(&self) -> &State {
+        &self.0.last().expect("path always has at least an initial state").0
+    }
+
+    /// The actions taken to reach the discovered state, in order.
+    pub fn into_actions(self) -> Vec<Action> {
+        self.0.into_iter().filter_map(|(_, action)| action).collect()
+    }
+}
+
+/// The frontier of unexplored states. [`SearchMode::BreadthFirst`] and
+/// [`SearchMode::DepthFirst`] share a `VecDeque`, differing only in which end they pop from;
+/// [`SearchMode::BestFirst`] uses a min-heap (via [`Reverse`]) keyed on [`Model::heuristic`].
+enum Frontier<State> {
+    Deque(VecDeque<State>),
+    Heap { heap: BinaryHeap<Reverse<(u64, u64)>>, states: HashMap<u64, State>, next_id: u64 },
+}
+
+impl<State> Frontier<State> {
+    fn new(mode: SearchMode) -> Self {
+        match mode {
+            SearchMode::BreadthFirst | SearchMode::DepthFirst => Frontier::Deque(VecDeque::new()),
+            SearchMode::BestFirst => {
+                Frontier::Heap { heap: BinaryHeap::new(), states: HashMap::new(), next_id: 0 }
+            }
+        }
+    }
+
+    fn push(&mut self, state: State, cost: u64) {
+        match self {
+            Frontier::Deque(deque) => deque.push_back(state),
+            Frontier::Heap { heap, states, next_id } => {
+                let id = *next_id;
+                *next_id += 1;
+                heap.push(Reverse((cost, id)));
+                states.insert(id, state);
+            }
+        }
+    }
+
+    fn pop(&mut self, mode: SearchMode) -> Option<State> {
+        match self {
+            Frontier::Deque(deque) => match mode {
+                SearchMode::BreadthFirst => deque.pop_front(),
+                SearchMode::DepthFirst => deque.pop_back(),
+                SearchMode::BestFirst => unreachable!("BestFirst always uses the heap frontier"),
+            },
+            Frontier::Heap { heap, states, .. } => {
+                let Reverse((_cost, id)) = heap.pop()?;
+                states.remove(&id)
+            }
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            Frontier::Deque(deque) => deque.len(),
+            Frontier::Heap { heap, .. } => heap.len(),
+        }
+    }
+}
+
+/// Live counters tracked while a [`Checker::check`] run is in progress, readable mid-run from a
+/// callback registered via [`Checker::on_progress`]. Kept behind atomics so they remain correct
+/// once the exploration is parallelized.
+#[derive(Default)]
+pub struct CheckStats {
+    states_generated: AtomicU64,
+    states_deduplicated: AtomicU64,
+    states_visited: AtomicU64,
+    max_frontier_size: AtomicU64,
+    elapsed_nanos: AtomicU64,
+}
+
+impl CheckStats {
+    /// How many candidate states `next_state` produced, including ones already visited.
+    pub fn states_generated(&self) -> u64 {
+        self.states_generated.load(Ordering::Relaxed)
+    }
+
+    /// How many candidate states were discarded because they had already been visited.
+    pub fn states_deduplicated(&self) -> u64 {
+        self.states_deduplicated.load(Ordering::Relaxed)
+    }
+
+    /// How many distinct states have been visited so far.
+    pub fn states_visited(&self) -> u64 {
+        self.states_visited.load(Ordering::Relaxed)
+    }
+
+    /// The largest the frontier has grown so far.
+    pub fn max_frontier_size(&self) -> u64 {
+        self.max_frontier_size.load(Ordering::Relaxed)
+    }
+
+    /// Wall-clock time spent checking so far.
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.elapsed_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Throughput so far, in states visited per second.
+    pub fn states_per_sec(&self) -> f64 {
+        let secs = self.elapsed().as_secs_f64();
+        if secs == 0.0 {
+            0.0
+        } else {
+            self.states_visited() as f64 / secs
+        }
+    }
+
+    fn record_generated(&self) {
+        self.states_generated.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_deduplicated(&self) {
+        self.states_deduplicated.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_visited(&self) {
+        self.states_visited.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn record_frontier_size(&self, size: u64) {
+        self.max_frontier_size.fetch_max(size, Ordering::Relaxed);
+    }
+
+    fn record_elapsed(&self, elapsed: Duration) {
+        self.elapsed_nanos.store(elapsed.as_nanos() as u64, Ordering::Relaxed);
+    }
+}
+
+/// The outcome of a completed [`Checker::check`] run: the first path discovered for each
+/// property that was violated (`always`) or witnessed (`sometimes`, and — since this checker
+/// explores a graph rather than individual paths and does no fairness analysis — `eventually` as
+/// an existential approximation: "holds in at least one reached state" rather than true
+/// liveness).
+pub struct CheckResult<M: Model> {
+    discoveries: HashMap<&'static str, Path<M::State, M::Action>>,
+}
+
+impl<M: Model> CheckResult<M> {
+    /// The discovery path for a given property name, if one was found.
+    pub fn discovery(&self, property_name: &str) -> Option<&Path<M::State, M::Action>> {
+        self.discoveries.get(property_name)
+    }
+}
+
+type ProgressCallback = Box<dyn FnMut(&CheckStats)>;
+
+/// Explores the state graph of a [`Model`], checking its properties along the way.
+pub struct Checker<M: Model> {
+    model: M,
+    progress_interval: u64,
+    on_progress: Option<ProgressCallback>,
+}
+
+impl<M: Model> Checker<M> {
+    pub fn new(model: M) -> Self {
+        Checker { model, progress_interval: DEFAULT_PROGRESS_INTERVAL, on_progress: None }
+    }
+
+    /// Registers a callback invoked every `interval` states visited during [`Checker::check`],
+    /// so long-running checks can report throughput and elapsed time without the caller bolting
+    /// on their own timers.
+    pub fn on_progress(
+        &mut self,
+        interval: u64,
+        callback: impl FnMut(&CheckStats) + 'static,
+    ) -> &mut Self {
+        self.progress_interval = interval.max(1);
+        self.on_progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Explores the states reachable from the model's initial states, in the order determined
+    /// by `mode`, until every property has a discovery or no unexplored state is left, and
+    /// returns the first discovery made for each property alongside the final [`CheckStats`] for
+    /// the run. A model with no properties is not explored at all.
+    pub fn check(&mut self, mode: SearchMode) -> (CheckResult<M>, CheckStats)
+    where
+        M::State: Clone + Eq + Hash,
+        M::Action: Clone,
+    {
+        let Checker { model, progress_interval, on_progress } = self;
+        let progress_interval = *progress_interval;
+
+        let stats = CheckStats::default();
+        let start = Instant::now();
+        let properties = model.properties();
+        let mut discoveries = HashMap::new();
+        let mut visited = HashSet::new();
+        let mut predecessors: HashMap<M::State, (M::State, M::Action)> = HashMap::new();
+        let mut frontier = Frontier::new(mode);
+
+        for state in model.init_states() {
+            if visited.insert(state.clone()) {
+                let cost = model.heuristic(&state);
+                frontier.push(state, cost);
+            }
+        }
+        stats.record_frontier_size(frontier.len() as u64);
+
+        let mut actions = Vec::new();
+        while discoveries.len() < properties.len() {
+            let Some(state) = frontier.pop(mode) else { break };
+            stats.record_visited();
+
+            for property in &properties {
+                if discoveries.contains_key(property.name) {
+                    continue;
+                }
+                let holds = (property.condition)(model, &state);
+                let is_discovery = match property.expectation {
+                    Expectation::Always => !holds,
+                    Expectation::Sometimes => holds,
+                    // This checker explores a state graph rather than individual paths and does
+                    // no fairness analysis, so it cannot confirm true liveness. `Eventually` is
+                    // explicitly downgraded to the same existential check as `Sometimes`: reached
+                    // once the condition holds in *some* visited state.
+                    Expectation::Eventually => holds,
+                };
+                if is_discovery {
+                    discoveries.insert(property.name, path_to(&predecessors, state.clone()));
+                }
+            }
+
+            actions.clear();
+            model.actions(&state, &mut actions);
+            for action in actions.drain(..) {
+                if let Some(next) = model.next_state(&state, action.clone()) {
+                    stats.record_generated();
+                    if visited.insert(next.clone()) {
+                        predecessors.insert(next.clone(), (state.clone(), action));
+                        let cost = model.heuristic(&next);
+                        frontier.push(next, cost);
+                    } else {
+                        stats.record_deduplicated();
+                    }
+                }
+            }
+            stats.record_frontier_size(frontier.len() as u64);
+
+            if let Some(on_progress) = on_progress {
+                if stats.states_visited() % progress_interval == 0 {
+                    stats.record_elapsed(start.elapsed());
+                    on_progress(&stats);
+                }
+            }
+        }
+
+        stats.record_elapsed(start.elapsed());
+        (CheckResult { discoveries }, stats)
+    }
+
+    /// Lazily explores the state graph in `mode` order, yielding every state (with its path from
+    /// an initial state) that witnesses a `sometimes`/`eventually` property or violates an
+    /// `always` property. Unlike [`Checker::check`], which stops at the first discovery per
+    /// property, this keeps exploring on every call to `next()`, so a caller can enumerate every
+    /// witness, or take just the first few and drop the rest without paying for a full sweep.
+    pub fn iter_discoveries(&self, mode: SearchMode) -> Discoveries<'_, M>
+    where
+        M::State: Clone + Eq + Hash,
+    {
+        let properties = self.model.properties();
+        let mut visited = HashSet::new();
+        let mut frontier = Frontier::new(mode);
+        for state in self.model.init_states() {
+            if visited.insert(state.clone()) {
+                let cost = self.model.heuristic(&state);
+                frontier.push(state, cost);
+            }
+        }
+        Discoveries {
+            model: &self.model,
+            mode,
+            properties,
+            frontier,
+            visited,
+            predecessors: HashMap::new(),
+        }
+    }
+}
+
+/// Reconstructs the path from an initial state to `state` by walking `predecessors` backwards.
+fn path_to<State, Action>(
+    predecessors: &HashMap<State, (State, Action)>,
+    state: State,
+) -> Path<State, Action>
+where
+    State: Clone + Eq + Hash,
+    Action: Clone,
+{
+    let mut steps = Vec::new();
+    let mut current = state;
+    loop {
+        match predecessors.get(&current) {
+            Some((prev, action)) => {
+                steps.push((current, Some(action.clone())));
+                current = prev.clone();
+            }
+            None => {
+                steps.push((current, None));
+                break;
+            }
+        }
+    }
+    steps.reverse();
+    Path(steps)
+}
+
+/// A lazy, resumable iterator over discovered witnesses/counterexamples, returned by
+/// [`Checker::iter_discoveries`]. Owns the frontier and visited set so that exploration picks up
+/// exactly where the previous call to `next()` left off.
+pub struct Discoveries<'a, M: Model> {
+    model: &'a M,
+    mode: SearchMode,
+    properties: Vec<crate::Property<M>>,
+    frontier: Frontier<M::State>,
+    visited: HashSet<M::State>,
+    predecessors: HashMap<M::State, (M::State, M::Action)>,
+}
+
+impl<'a, M: Model> Iterator for Discoveries<'a, M>
+where
+    M::State: Clone + Eq + Hash,
+    M::Action: Clone,
+{
+    type Item = Path<M::State, M::Action>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut actions = Vec::new();
+        while let Some(state) = self.frontier.pop(self.mode) {
+            let is_discovery = self.properties.iter().any(|property| {
+                let holds = (property.condition)(self.model, &state);
+                match property.expectation {
+                    Expectation::Always => !holds,
+                    Expectation::Sometimes => holds,
+                    // See the matching comment in `Checker::check`: this checker cannot confirm
+                    // true liveness, so `Eventually` is treated as the same existential check as
+                    // `Sometimes`.
+                    Expectation::Eventually => holds,
+                }
+            });
+
+            actions.clear();
+            self.model.actions(&state, &mut actions);
+            for action in actions.drain(..) {
+                if let Some(next) = self.model.next_state(&state, action.clone()) {
+                    if self.visited.insert(next.clone()) {
+                        self.predecessors.insert(next.clone(), (state.clone(), action));
+                        let cost = self.model.heuristic(&next);
+                        self.frontier.push(next, cost);
+                    }
+                }
+            }
+
+            if is_discovery {
+                return Some(path_to(&self.predecessors, state));
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util::binary_clock::BinaryClock;
+    use crate::test_util::linear_equation_solver::LinearEquation;
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    #[test]
+    fn check_reports_accurate_stats_and_invokes_progress_callback() {
+        let progress_calls = Rc::new(RefCell::new(0u64));
+        let progress_calls_in_callback = Rc::clone(&progress_calls);
+
+        let mut checker = BinaryClock.checker();
+        checker.on_progress(1, move |_stats| {
+            *progress_calls_in_callback.borrow_mut() += 1;
+        });
+        let (_result, stats) = checker.check(SearchMode::BreadthFirst);
+
+        // BinaryClock has exactly two reachable states (0 and 1), each generating one transition
+        // back to the other, so every count below is exact rather than a loose bound.
+        assert_eq!(stats.states_visited(), 2);
+        assert_eq!(stats.states_generated(), 2);
+        assert_eq!(stats.states_deduplicated(), 2);
+        assert_eq!(stats.max_frontier_size(), 2);
+        assert_eq!(*progress_calls.borrow(), 2);
+    }
+
+    #[test]
+    fn best_first_uses_heuristic_to_reach_witness_in_fewer_states_than_bfs() {
+        let mut bfs_checker = LinearEquation { a: 3, b: 5, c: 7 }.checker();
+        let (bfs_result, bfs_stats) = bfs_checker.check(SearchMode::BreadthFirst);
+        assert!(bfs_result.discovery("solvable").is_some());
+
+        let mut best_first_checker = LinearEquation { a: 3, b: 5, c: 7 }.checker();
+        let (best_first_result, best_first_stats) = best_first_checker.check(SearchMode::BestFirst);
+        assert!(best_first_result.discovery("solvable").is_some());
+
+        assert!(
+            best_first_stats.states_visited() < bfs_stats.states_visited(),
+            "best-first visited {} states, bfs visited {}",
+            best_first_stats.states_visited(),
+            bfs_stats.states_visited(),
+        );
+    }
+
+    #[test]
+    fn iter_discoveries_resumes_across_next_calls() {
+        let checker = LinearEquation { a: 2, b: 3, c: 7 }.checker();
+        let mut discoveries = checker.iter_discoveries(SearchMode::BreadthFirst);
+
+        let first_batch: Vec<_> = discoveries.by_ref().take(3).collect();
+        assert_eq!(first_batch.len(), 3);
+
+        let second_batch: Vec<_> = discoveries.by_ref().take(3).collect();
+        assert_eq!(second_batch.len(), 3);
+
+        let first_states: HashSet<_> = first_batch.iter().map(|p| *p.last_state()).collect();
+        let second_states: HashSet<_> = second_batch.iter().map(|p| *p.last_state()).collect();
+        assert!(
+            first_states.is_disjoint(&second_states),
+            "second batch repeated a witness already yielded in the first"
+        );
+    }
+
+    /// A model with an `eventually` property, used to pin down how this checker treats liveness.
+    struct Counter;
+
+    impl Model for Counter {
+        type State = u8;
+        type Action = ();
+
+        fn init_states(&self) -> Vec<Self::State> {
+            vec![0]
+        }
+
+        fn actions(&self, state: &Self::State, actions: &mut Vec<Self::Action>) {
+            if *state < 3 {
+                actions.push(());
+            }
+        }
+
+        fn next_state(&self, state: &Self::State, _action: Self::Action) -> Option<Self::State> {
+            Some(state + 1)
+        }
+
+        fn properties(&self) -> Vec<crate::Property<Self>> {
+            vec![crate::Property::eventually("reaches 2", |_, state| *state == 2)]
+        }
+    }
+
+    #[test]
+    fn eventually_is_witnessed_as_an_existential_check_not_true_liveness() {
+        let mut checker = Counter.checker();
+        let (result, _stats) = checker.check(SearchMode::BreadthFirst);
+        let path = result.discovery("reaches 2").expect("eventually property should be witnessed");
+        assert_eq!(*path.last_state(), 2);
+    }
+}
@@ -0,0 +1,84 @@
+//! Stateright is a library for specifying state machines and model checking invariants over
+//! them.
+//!
+//! A [`Model`] describes a state machine: its initial states, the actions available from a
+//! given state, and the transition those actions cause. A [`Checker`] explores the resulting
+//! state graph and evaluates the model's [`Property`] list against every state it discovers.
+
+pub mod bench;
+mod checker;
+pub mod test_util;
+
+pub use checker::*;
+
+/// A state machine that can be explored by a [`Checker`].
+pub trait Model: Sized {
+    /// The type of states for this model.
+    type State;
+
+    /// The type of actions that transition between states for this model.
+    type Action;
+
+    /// Returns the initial possible states for this model.
+    fn init_states(&self) -> Vec<Self::State>;
+
+    /// Collects the actions available in a given state.
+    fn actions(&self, state: &Self::State, actions: &mut Vec<Self::Action>);
+
+    /// Converts a state and an action into a resulting state, or `None` if the action does not
+    /// apply to the state.
+    fn next_state(&self, state: &Self::State, action: Self::Action) -> Option<Self::State>;
+
+    /// Generates the properties that this model should maintain.
+    fn properties(&self) -> Vec<Property<Self>> {
+        Vec::new()
+    }
+
+    /// Estimates how close a state is to satisfying a `sometimes`/`eventually` property, for use
+    /// by [`SearchMode::BestFirst`]. Lower is "closer." The default of `0` makes best-first
+    /// search degrade to plain breadth-first search, so models that do not implement this are
+    /// unaffected.
+    fn heuristic(&self, _state: &Self::State) -> u64 {
+        0
+    }
+
+    /// Begins a [`Checker`] for this model.
+    fn checker(self) -> Checker<Self> {
+        Checker::new(self)
+    }
+}
+
+/// The expected behavior of a [`Property`] over the course of a model's execution.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Expectation {
+    /// The property is expected to hold in every state.
+    Always,
+    /// The property is expected to hold in some reachable state.
+    Sometimes,
+    /// The property is expected to eventually hold and then continue to hold.
+    Eventually,
+}
+
+/// A named invariant that a [`Checker`] evaluates against the states of a [`Model`].
+pub struct Property<M: Model> {
+    pub expectation: Expectation,
+    pub name: &'static str,
+    pub condition: fn(&M, &M::State) -> bool,
+}
+
+impl<M: Model> Property<M> {
+    /// A property that is expected to hold in every state.
+    pub fn always(name: &'static str, condition: fn(&M, &M::State) -> bool) -> Self {
+        Property { expectation: Expectation::Always, name, condition }
+    }
+
+    /// A property that is expected to hold in at least one reachable state.
+    pub fn sometimes(name: &'static str, condition: fn(&M, &M::State) -> bool) -> Self {
+        Property { expectation: Expectation::Sometimes, name, condition }
+    }
+
+    /// A property that is expected to eventually hold and then continue to hold.
+    pub fn eventually(name: &'static str, condition: fn(&M, &M::State) -> bool) -> Self {
+        Property { expectation: Expectation::Eventually, name, condition }
+    }
+}
@@ -92,5 +92,18 @@ pub mod linear_equation_solver {
                 }),
             ]
         }
+
+        fn heuristic(&self, state: &Self::State) -> u64 {
+            let LinearEquation { a, b, c } = self;
+            let (x, y) = state;
+
+            // dereference and enable wrapping so the equation is succinct
+            use std::num::Wrapping;
+            let (x, y) = (Wrapping(*x), Wrapping(*y));
+            let (a, b, c) = (Wrapping(*a), Wrapping(*b), Wrapping(*c));
+
+            let d = (a*x + b*y - c).0 as u64;
+            d.min(256 - d)
+        }
     }
 }